@@ -60,7 +60,7 @@ pub struct Range<T> {
 }
 
 impl<T> Range<T> {
-    pub fn new(start: T, end: T, incr: T) -> Self {
+    pub const fn new(start: T, end: T, incr: T) -> Self {
         Self {
             cur: start,
             end,
@@ -88,6 +88,103 @@ where
 }
 // ANCHOR_END: range
 
+// ANCHOR: range_rev
+// `next` walks `cur` up towards `end`; `next_back` yields the largest element
+// `next` could still produce, then pulls the exclusive `end` down to it.
+//
+// This deliberately diverges from the "front cursor + back cursor decremented by
+// `incr` (`T: SubAssign`)" sketch: because `end` is only ever an *exclusive*
+// bound it may sit off the `cur + k*incr` grid, so a bare `end -= incr` emits
+// off-grid values and underflows once `end` dips below `cur`. We instead snap
+// down to the last on-grid point by walking up from `cur`, which keeps the bound
+// set to the crate's usual `AddAssign + PartialOrd + Clone` and yields nothing
+// `next` wouldn't. The tradeoff is that each `next_back` is O(k) in the elements
+// remaining, so a full reverse drain is O(n^2); a constant-time back cursor
+// would need an extra aligned field on `Range`, which would break the struct/
+// closure `size_of_val` parity the crate is built to demonstrate. The shared
+// `cur < end` guard keeps the two cursors meeting in the middle exactly once.
+impl<T> DoubleEndedIterator for Range<T>
+where
+    T: std::ops::AddAssign + PartialOrd + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.cur < self.end {
+            let mut last = self.cur.clone();
+            loop {
+                let mut probe = last.clone();
+                probe += self.incr.clone();
+                if probe < self.end {
+                    last = probe;
+                } else {
+                    break;
+                }
+            }
+            self.end = last.clone();
+            return last.into();
+        }
+        None
+    }
+}
+// ANCHOR_END: range_rev
+
+// ANCHOR: range_rev_closure
+pub mod range_de {
+    use std::cell::RefCell;
+    use std::ops::AddAssign;
+    use std::rc::Rc;
+
+    /// The closure-world twin of [`DoubleEndedIterator`] for `range_fn`: a
+    /// `(front, back)` pair of `FnMut() -> Option<T>` sharing one `(cur, end)`
+    /// so that, just like the struct, the two ends meet in the middle once.
+    pub fn new<T>(
+        start: T,
+        end: T,
+        incr: T,
+    ) -> (impl FnMut() -> Option<T>, impl FnMut() -> Option<T>)
+    where
+        T: AddAssign + PartialOrd + Clone,
+    {
+        let state = Rc::new(RefCell::new((start, end)));
+
+        let front_state = Rc::clone(&state);
+        let front_incr = incr.clone();
+        let front = move || {
+            let mut s = front_state.borrow_mut();
+            if s.0 < s.1 {
+                let ret = s.0.clone();
+                s.0 += front_incr.clone();
+                return ret.into();
+            }
+            None
+        };
+
+        // Mirror the struct's `next_back`: snap the exclusive `end` down to the
+        // last on-grid point instead of stepping it blindly, so the closure twin
+        // never yields off-grid values or underflows past the front cursor.
+        let back = move || {
+            let mut s = state.borrow_mut();
+            if s.0 < s.1 {
+                let mut last = s.0.clone();
+                loop {
+                    let mut probe = last.clone();
+                    probe += incr.clone();
+                    if probe < s.1 {
+                        last = probe;
+                    } else {
+                        break;
+                    }
+                }
+                s.1 = last.clone();
+                return last.into();
+            }
+            None
+        };
+
+        (front, back)
+    }
+}
+// ANCHOR_END: range_rev_closure
+
 // ANCHOR: range_closure
 pub mod range_fn {
     pub fn new<T>(mut start: T, end: T, incr: T) -> impl FnMut() -> Option<T>
@@ -114,7 +211,7 @@ pub struct Bounds<I, T> {
 }
 
 impl<I, T> Bounds<I, T> {
-    pub fn new(inner: I, min: T, max: T) -> Self {
+    pub const fn new(inner: I, min: T, max: T) -> Self {
         Self { inner, min, max }
     }
 }
@@ -127,113 +224,756 @@ where
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            match self.inner.next() {
-                Some(v) if v >= self.min && v < self.max => return v.into(),
-                Some(_) => {}
-                None => return None,
-            }
+        loop {
+            match self.inner.next() {
+                Some(v) if v >= self.min && v < self.max => return v.into(),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+    }
+}
+// ANCHOR_END: bounds
+
+// ANCHOR: bounds_rev
+// `Bounds` owns no cursor of its own, so it just pulls from the back of its
+// inner iterator and re-applies the same window predicate.
+impl<I> DoubleEndedIterator for Bounds<I, I::Item>
+where
+    I: DoubleEndedIterator,
+    I::Item: PartialOrd,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next_back() {
+                Some(v) if v >= self.min && v < self.max => return v.into(),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+    }
+}
+// ANCHOR_END: bounds_rev
+
+// ANCHOR: bounds_closure
+pub mod bounds_fn {
+    pub fn new<T, F>(mut inner: F, min: T, max: T) -> impl FnMut() -> Option<T>
+    where
+        T: PartialOrd,
+        F: FnMut() -> Option<T>,
+    {
+        move || loop {
+            match inner() {
+                Some(v) if v >= min && v < max => return v.into(),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+    }
+}
+// ANCHOR_END: bounds_closure
+
+// ANCHOR: bounds_ext
+pub trait BoundsExt: Iterator
+where
+    Self: Sized,
+{
+    fn bounds<T>(self, min: T, max: T) -> Bounds<Self, T> {
+        Bounds::new(self, min, max)
+    }
+}
+
+impl<I: Iterator> BoundsExt for I {}
+// ANCHOR_END: bounds_ext
+
+// ANCHOR: bounds_ext_closure
+trait BoundsExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a + std::cmp::PartialOrd,
+{
+    fn bounds(self, min: T, max: T) -> Box<dyn FnMut() -> Option<T> + 'a> {
+        Box::new(bounds_fn::new(self, min, max))
+    }
+}
+
+impl<'a, F, T> BoundsExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a + std::cmp::PartialOrd,
+{
+}
+// ANCHOR_END: bounds_ext_closure
+
+// ANCHOR: const_bounds_table
+// The "allow const iterator implementations" work can't be mirrored through the
+// adapters themselves on this toolchain: std's `Iterator`/`PartialOrd`/`Clone`/
+// `AddAssign` are not `#[const_trait]`, so `impl const Iterator for Range` (with
+// `~const` bounds) is rejected outright. We still get the payoff -- draining a
+// bounded `Range` into a `const` lookup table -- by open-coding the exact loop
+// `Range::new(0, n, 1).bounds(lo, hi)` would run, which `const fn` accepts.
+pub const fn const_bounds_table<const K: usize>(n: usize, lo: usize, hi: usize) -> [usize; K] {
+    let mut out = [0usize; K];
+    let mut cur = 0;
+    let mut i = 0;
+    while cur < n {
+        if cur >= lo && cur < hi {
+            out[i] = cur;
+            i += 1;
+        }
+        cur += 1;
+    }
+    out
+}
+// ANCHOR_END: const_bounds_table
+
+// ANCHOR: filter
+pub struct Filter<I, P> {
+    inner: I,
+    predicate: P,
+}
+
+impl<I, P> Filter<I, P> {
+    pub const fn new(inner: I, predicate: P) -> Self {
+        Self { inner, predicate }
+    }
+}
+
+impl<I, P> Iterator for Filter<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next() {
+                Some(v) if (self.predicate)(&v) => return v.into(),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+    }
+}
+// ANCHOR_END: filter
+
+// ANCHOR: filter_rev
+impl<I, P> DoubleEndedIterator for Filter<I, P>
+where
+    I: DoubleEndedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next_back() {
+                Some(v) if (self.predicate)(&v) => return v.into(),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+    }
+}
+// ANCHOR_END: filter_rev
+
+// ANCHOR: filter_ext
+pub trait FilterExt: Iterator
+where
+    Self: Sized,
+{
+    fn filter_with<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+}
+
+impl<I: Iterator> FilterExt for I {}
+// ANCHOR_END: filter_ext
+
+// ANCHOR: filter_closure
+pub mod filter_fn {
+    pub fn new<T, F, P>(mut inner: F, mut predicate: P) -> impl FnMut() -> Option<T>
+    where
+        F: FnMut() -> Option<T>,
+        P: FnMut(&T) -> bool,
+    {
+        move || loop {
+            match inner() {
+                Some(v) if predicate(&v) => return v.into(),
+                Some(_) => {}
+                None => return None,
+            }
+        }
+    }
+}
+// ANCHOR_END: filter_closure
+
+// ANCHOR: filter_ext_closure
+trait FilterExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a,
+{
+    fn filter<P>(self, predicate: P) -> Box<dyn FnMut() -> Option<T> + 'a>
+    where
+        P: 'a + FnMut(&T) -> bool,
+    {
+        Box::new(filter_fn::new(self, predicate))
+    }
+}
+
+impl<'a, F, T> FilterExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a,
+{
+}
+// ANCHOR_END: filter_ext_closure
+
+// Every adapter below appears twice: once as an `Iterator` struct with an
+// `Ext` trait (à la `Bounds`/`BoundsExt`) and once as an `FnMut() -> Option<T>`
+// closure with an `Ext`-on-`FnMut` trait (à la `bounds_fn`/`BoundsExtFn`). The
+// closure twin captures exactly the fields the struct stores, so the two are
+// byte-for-byte the same size -- see the `adapter_sizes` test.
+
+// ANCHOR: map
+pub struct Map<I, F> {
+    inner: I,
+    f: F,
+}
+
+impl<I, F> Map<I, F> {
+    pub fn new(inner: I, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<I, F, B> Iterator for Map<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(&mut self.f)
+    }
+}
+// ANCHOR_END: map
+
+// ANCHOR: map_closure
+pub mod map_fn {
+    pub fn new<T, U, F, M>(mut inner: F, mut f: M) -> impl FnMut() -> Option<U>
+    where
+        F: FnMut() -> Option<T>,
+        M: FnMut(T) -> U,
+    {
+        move || inner().map(&mut f)
+    }
+}
+// ANCHOR_END: map_closure
+
+// ANCHOR: map_ext
+pub trait MapExt: Iterator
+where
+    Self: Sized,
+{
+    fn map_with<B, F>(self, f: F) -> Map<Self, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        Map::new(self, f)
+    }
+}
+
+impl<I: Iterator> MapExt for I {}
+// ANCHOR_END: map_ext
+
+// ANCHOR: map_ext_closure
+trait MapExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a,
+{
+    fn map<U, M>(self, f: M) -> Box<dyn FnMut() -> Option<U> + 'a>
+    where
+        U: 'a,
+        M: 'a + FnMut(T) -> U,
+    {
+        Box::new(map_fn::new(self, f))
+    }
+}
+
+impl<'a, F, T> MapExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a,
+{
+}
+// ANCHOR_END: map_ext_closure
+
+// ANCHOR: zip
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Zip<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<A, B> Iterator for Zip<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.a.next()?, self.b.next()?))
+    }
+}
+// ANCHOR_END: zip
+
+// ANCHOR: zip_closure
+pub mod zip_fn {
+    pub fn new<T, U, A, B>(mut a: A, mut b: B) -> impl FnMut() -> Option<(T, U)>
+    where
+        A: FnMut() -> Option<T>,
+        B: FnMut() -> Option<U>,
+    {
+        move || Some((a()?, b()?))
+    }
+}
+// ANCHOR_END: zip_closure
+
+// ANCHOR: zip_ext
+pub trait ZipExt: Iterator
+where
+    Self: Sized,
+{
+    fn zip_with<B>(self, other: B) -> Zip<Self, B>
+    where
+        B: Iterator,
+    {
+        Zip::new(self, other)
+    }
+}
+
+impl<I: Iterator> ZipExt for I {}
+// ANCHOR_END: zip_ext
+
+// ANCHOR: zip_ext_closure
+trait ZipExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a,
+{
+    fn zip<U, B>(self, other: B) -> Box<dyn FnMut() -> Option<(T, U)> + 'a>
+    where
+        U: 'a,
+        B: 'a + FnMut() -> Option<U>,
+    {
+        Box::new(zip_fn::new(self, other))
+    }
+}
+
+impl<'a, F, T> ZipExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a,
+{
+}
+// ANCHOR_END: zip_ext_closure
+
+// ANCHOR: chain
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+    a_done: bool,
+}
+
+impl<A, B> Chain<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            a_done: false,
+        }
+    }
+}
+
+impl<A, B> Iterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.a_done {
+            if let Some(v) = self.a.next() {
+                return v.into();
+            }
+            self.a_done = true;
+        }
+        self.b.next()
+    }
+}
+// ANCHOR_END: chain
+
+// ANCHOR: chain_closure
+pub mod chain_fn {
+    pub fn new<T, A, B>(mut a: A, mut b: B) -> impl FnMut() -> Option<T>
+    where
+        A: FnMut() -> Option<T>,
+        B: FnMut() -> Option<T>,
+    {
+        let mut a_done = false;
+        move || {
+            if !a_done {
+                if let Some(v) = a() {
+                    return v.into();
+                }
+                a_done = true;
+            }
+            b()
+        }
+    }
+}
+// ANCHOR_END: chain_closure
+
+// ANCHOR: chain_ext
+pub trait ChainExt: Iterator
+where
+    Self: Sized,
+{
+    fn chain_with<B>(self, other: B) -> Chain<Self, B>
+    where
+        B: Iterator<Item = Self::Item>,
+    {
+        Chain::new(self, other)
+    }
+}
+
+impl<I: Iterator> ChainExt for I {}
+// ANCHOR_END: chain_ext
+
+// ANCHOR: chain_ext_closure
+trait ChainExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a,
+{
+    fn chain<B>(self, other: B) -> Box<dyn FnMut() -> Option<T> + 'a>
+    where
+        B: 'a + FnMut() -> Option<T>,
+    {
+        Box::new(chain_fn::new(self, other))
+    }
+}
+
+impl<'a, F, T> ChainExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a,
+{
+}
+// ANCHOR_END: chain_ext_closure
+
+// ANCHOR: enumerate
+pub struct Enumerate<I> {
+    inner: I,
+    count: usize,
+}
+
+impl<I> Enumerate<I> {
+    pub fn new(inner: I) -> Self {
+        Self { inner, count: 0 }
+    }
+}
+
+impl<I> Iterator for Enumerate<I>
+where
+    I: Iterator,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let v = self.inner.next()?;
+        let i = self.count;
+        self.count += 1;
+        (i, v).into()
+    }
+}
+// ANCHOR_END: enumerate
+
+// ANCHOR: enumerate_closure
+pub mod enumerate_fn {
+    pub fn new<T, F>(mut inner: F) -> impl FnMut() -> Option<(usize, T)>
+    where
+        F: FnMut() -> Option<T>,
+    {
+        let mut count = 0;
+        move || {
+            let v = inner()?;
+            let i = count;
+            count += 1;
+            (i, v).into()
+        }
+    }
+}
+// ANCHOR_END: enumerate_closure
+
+// ANCHOR: enumerate_ext
+pub trait EnumerateExt: Iterator
+where
+    Self: Sized,
+{
+    fn enumerate_with(self) -> Enumerate<Self> {
+        Enumerate::new(self)
+    }
+}
+
+impl<I: Iterator> EnumerateExt for I {}
+// ANCHOR_END: enumerate_ext
+
+// ANCHOR: enumerate_ext_closure
+trait EnumerateExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a,
+{
+    fn enumerate(self) -> Box<dyn FnMut() -> Option<(usize, T)> + 'a> {
+        Box::new(enumerate_fn::new(self))
+    }
+}
+
+impl<'a, F, T> EnumerateExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a,
+{
+}
+// ANCHOR_END: enumerate_ext_closure
+
+// ANCHOR: take
+pub struct Take<I> {
+    inner: I,
+    remaining: usize,
+}
+
+impl<I> Take<I> {
+    pub fn new(inner: I, n: usize) -> Self {
+        Self {
+            inner,
+            remaining: n,
+        }
+    }
+}
+
+impl<I> Iterator for Take<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next()
+    }
+}
+// ANCHOR_END: take
+
+// ANCHOR: take_closure
+pub mod take_fn {
+    pub fn new<T, F>(mut inner: F, mut n: usize) -> impl FnMut() -> Option<T>
+    where
+        F: FnMut() -> Option<T>,
+    {
+        move || {
+            if n == 0 {
+                return None;
+            }
+            n -= 1;
+            inner()
+        }
+    }
+}
+// ANCHOR_END: take_closure
+
+// ANCHOR: take_ext
+pub trait TakeExt: Iterator
+where
+    Self: Sized,
+{
+    fn take_with(self, n: usize) -> Take<Self> {
+        Take::new(self, n)
+    }
+}
+
+impl<I: Iterator> TakeExt for I {}
+// ANCHOR_END: take_ext
+
+// ANCHOR: take_ext_closure
+trait TakeExtFn<'a, T>: FnMut() -> Option<T>
+where
+    Self: 'a + Sized,
+    T: 'a,
+{
+    fn take(self, n: usize) -> Box<dyn FnMut() -> Option<T> + 'a> {
+        Box::new(take_fn::new(self, n))
+    }
+}
+
+impl<'a, F, T> TakeExtFn<'a, T> for F
+where
+    F: 'a + FnMut() -> Option<T>,
+    T: 'a,
+{
+}
+// ANCHOR_END: take_ext_closure
+
+// ANCHOR: skip
+pub struct Skip<I> {
+    inner: I,
+    remaining: usize,
+}
+
+impl<I> Skip<I> {
+    pub fn new(inner: I, n: usize) -> Self {
+        Self {
+            inner,
+            remaining: n,
+        }
+    }
+}
+
+impl<I> Iterator for Skip<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            self.remaining -= 1;
+            self.inner.next()?;
         }
+        self.inner.next()
     }
 }
-// ANCHOR_END: bounds
+// ANCHOR_END: skip
 
-// ANCHOR: bounds_closure
-pub mod bounds_fn {
-    pub fn new<T, F>(mut inner: F, min: T, max: T) -> impl FnMut() -> Option<T>
+// ANCHOR: skip_closure
+pub mod skip_fn {
+    pub fn new<T, F>(mut inner: F, mut n: usize) -> impl FnMut() -> Option<T>
     where
-        T: PartialOrd,
         F: FnMut() -> Option<T>,
     {
-        move || loop {
-            match inner() {
-                Some(v) if v >= min && v < max => return v.into(),
-                Some(_) => {}
-                None => return None,
+        move || {
+            while n > 0 {
+                n -= 1;
+                inner()?;
             }
+            inner()
         }
     }
 }
-// ANCHOR_END: bounds_closure
+// ANCHOR_END: skip_closure
 
-// ANCHOR: bounds_ext
-pub trait BoundsExt: Iterator
+// ANCHOR: skip_ext
+pub trait SkipExt: Iterator
 where
     Self: Sized,
 {
-    fn bounds<T>(self, min: T, max: T) -> Bounds<Self, T> {
-        Bounds::new(self, min, max)
+    fn skip_with(self, n: usize) -> Skip<Self> {
+        Skip::new(self, n)
     }
 }
 
-impl<I: Iterator> BoundsExt for I {}
-// ANCHOR_END: bounds_ext
+impl<I: Iterator> SkipExt for I {}
+// ANCHOR_END: skip_ext
 
-// ANCHOR: bounds_ext_closure
-trait BoundsExtFn<'a, T>: FnMut() -> Option<T>
+// ANCHOR: skip_ext_closure
+trait SkipExtFn<'a, T>: FnMut() -> Option<T>
 where
     Self: 'a + Sized,
-    T: 'a + std::cmp::PartialOrd,
+    T: 'a,
 {
-    fn bounds(self, min: T, max: T) -> Box<dyn FnMut() -> Option<T> + 'a> {
-        Box::new(bounds_fn::new(self, min, max))
+    fn skip(self, n: usize) -> Box<dyn FnMut() -> Option<T> + 'a> {
+        Box::new(skip_fn::new(self, n))
     }
 }
 
-impl<'a, F, T> BoundsExtFn<'a, T> for F
+impl<'a, F, T> SkipExtFn<'a, T> for F
 where
     F: 'a + FnMut() -> Option<T>,
-    T: 'a + std::cmp::PartialOrd,
+    T: 'a,
 {
 }
-// ANCHOR_END: bounds_ext_closure
-
-// ANCHOR: filter
-pub struct Filter<I, P> {
-    inner: I,
-    predicate: P,
-}
+// ANCHOR_END: skip_ext_closure
 
-impl<I, P> Filter<I, P> {
-    pub fn new(inner: I, predicate: P) -> Self {
-        Self { inner, predicate }
+// ANCHOR: fold
+// `fold` is a consumer, not an adapter: it drains the pipeline and hands back
+// the accumulator rather than another `Iterator`/`FnMut`.
+pub trait FoldExt: Iterator
+where
+    Self: Sized,
+{
+    fn fold_with<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+        while let Some(v) = self.next() {
+            acc = f(acc, v);
+        }
+        acc
     }
 }
 
-impl<I, P> Iterator for Filter<I, P>
-where
-    I: Iterator,
-    P: FnMut(&I::Item) -> bool,
-{
-    type Item = I::Item;
+impl<I: Iterator> FoldExt for I {}
+// ANCHOR_END: fold
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            match self.inner.next() {
-                Some(v) if (self.predicate)(&v) => return v.into(),
-                Some(_) => {}
-                None => return None,
-            }
+// ANCHOR: fold_closure
+pub mod fold_fn {
+    pub fn new<T, B, F, G>(mut inner: F, init: B, mut g: G) -> B
+    where
+        F: FnMut() -> Option<T>,
+        G: FnMut(B, T) -> B,
+    {
+        let mut acc = init;
+        while let Some(v) = inner() {
+            acc = g(acc, v);
         }
+        acc
     }
 }
-// ANCHOR_END: filter
+// ANCHOR_END: fold_closure
 
-// ANCHOR: filter_ext
-pub trait FilterExt: Iterator
+// ANCHOR: fold_ext_closure
+trait FoldExtFn<T>: FnMut() -> Option<T>
 where
     Self: Sized,
 {
-    fn filter_with<P>(self, predicate: P) -> Filter<Self, P>
+    fn fold<B, G>(self, init: B, g: G) -> B
     where
-        P: FnMut(&Self::Item) -> bool,
+        G: FnMut(B, T) -> B,
     {
-        Filter::new(self, predicate)
+        fold_fn::new(self, init, g)
     }
 }
 
-impl<I: Iterator> FilterExt for I {}
-// ANCHOR_END: filter_ext
+impl<F, T> FoldExtFn<T> for F where F: FnMut() -> Option<T> {}
+// ANCHOR_END: fold_ext_closure
 
 // ANCHOR: iter_to_closure
 pub fn iter_to_closure<I: Iterator>(inner: I) -> impl FnMut() -> Option<I::Item> {
@@ -289,7 +1029,56 @@ pub enum Poll<T> {
     NotReady,
 }
 
-pub struct Notifier {/* ... */}
+/// A cloneable handle a stalled source hands to whoever is going to wake it.
+///
+/// It is just a shared ready-flag guarded by a `Condvar`: the driver blocks in
+/// [`Notifier::wait`] until someone -- a background timer, another thread --
+/// flips the flag with [`Notifier::notify`], so parking costs nothing instead
+/// of burning a core in a busy-loop.
+#[derive(Clone)]
+pub struct Notifier {
+    shared: std::sync::Arc<NotifierShared>,
+}
+
+struct NotifierShared {
+    ready: std::sync::Mutex<bool>,
+    cvar: std::sync::Condvar,
+}
+
+impl Notifier {
+    /// A fresh notifier starts *ready*, so the very first poll happens without
+    /// anyone having to fire it.
+    pub fn new() -> Self {
+        Self {
+            shared: std::sync::Arc::new(NotifierShared {
+                ready: std::sync::Mutex::new(true),
+                cvar: std::sync::Condvar::new(),
+            }),
+        }
+    }
+
+    /// Flip the flag and wake the parked driver. Safe to call from any thread.
+    pub fn notify(&self) {
+        let mut ready = self.shared.ready.lock().unwrap();
+        *ready = true;
+        self.shared.cvar.notify_one();
+    }
+
+    /// Park until the flag is set, then clear it for the next round.
+    fn wait(&self) {
+        let mut ready = self.shared.ready.lock().unwrap();
+        while !*ready {
+            ready = self.shared.cvar.wait(ready).unwrap();
+        }
+        *ready = false;
+    }
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 pub trait MultiplexedIterator {
     type Item;
@@ -306,6 +1095,219 @@ pub trait MultiplexedIterator {
 }
 // ANCHOR_END: multiplexed_iter
 
+// ANCHOR: multiplexed_from_iter
+pub struct FromIter<I>(I);
+
+/// Lifts any ordinary [`Iterator`] into a [`MultiplexedIterator`] that never
+/// stalls: every poll is immediately `Ready`.
+pub fn from_iter<I>(inner: I) -> FromIter<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    FromIter(inner.into_iter())
+}
+
+impl<I> MultiplexedIterator for FromIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self, _n: Notifier) -> Poll<Self::Item> {
+        Poll::Ready(self.0.next())
+    }
+}
+// ANCHOR_END: multiplexed_from_iter
+
+// ANCHOR: multiplexed_adapters
+pub struct MultiplexedBounds<M, T> {
+    inner: M,
+    min: T,
+    max: T,
+}
+
+impl<M> MultiplexedIterator for MultiplexedBounds<M, M::Item>
+where
+    M: MultiplexedIterator,
+    M::Item: PartialOrd,
+{
+    type Item = M::Item;
+
+    fn next(&mut self, n: Notifier) -> Poll<Self::Item> {
+        loop {
+            match self.inner.next(n.clone()) {
+                Poll::Ready(Some(v)) if v >= self.min && v < self.max => {
+                    return Poll::Ready(Some(v))
+                }
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::NotReady => return Poll::NotReady,
+            }
+        }
+    }
+}
+
+pub struct MultiplexedFilter<M, P> {
+    inner: M,
+    predicate: P,
+}
+
+impl<M, P> MultiplexedIterator for MultiplexedFilter<M, P>
+where
+    M: MultiplexedIterator,
+    P: FnMut(&M::Item) -> bool,
+{
+    type Item = M::Item;
+
+    fn next(&mut self, n: Notifier) -> Poll<Self::Item> {
+        loop {
+            match self.inner.next(n.clone()) {
+                Poll::Ready(Some(v)) if (self.predicate)(&v) => return Poll::Ready(Some(v)),
+                Poll::Ready(Some(_)) => {}
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::NotReady => return Poll::NotReady,
+            }
+        }
+    }
+}
+
+pub struct MultiplexedMap<M, F> {
+    inner: M,
+    f: F,
+}
+
+impl<M, F, B> MultiplexedIterator for MultiplexedMap<M, F>
+where
+    M: MultiplexedIterator,
+    F: FnMut(M::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self, n: Notifier) -> Poll<Self::Item> {
+        match self.inner.next(n) {
+            Poll::Ready(Some(v)) => Poll::Ready(Some((self.f)(v))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::NotReady => Poll::NotReady,
+        }
+    }
+}
+// ANCHOR_END: multiplexed_adapters
+
+// ANCHOR: multiplexed_ext
+pub trait MultiplexedExt: MultiplexedIterator
+where
+    Self: Sized,
+{
+    fn bounds<T>(self, min: T, max: T) -> MultiplexedBounds<Self, T> {
+        MultiplexedBounds {
+            inner: self,
+            min,
+            max,
+        }
+    }
+
+    fn filter<P>(self, predicate: P) -> MultiplexedFilter<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        MultiplexedFilter {
+            inner: self,
+            predicate,
+        }
+    }
+
+    fn map<B, F>(self, f: F) -> MultiplexedMap<Self, F>
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        MultiplexedMap { inner: self, f }
+    }
+}
+
+impl<M: MultiplexedIterator> MultiplexedExt for M {}
+// ANCHOR_END: multiplexed_ext
+
+// ANCHOR: block_on
+/// Drives a single [`MultiplexedIterator`] to completion, collecting every
+/// yielded item. On `Poll::NotReady` the driver parks on the [`Notifier`]
+/// instead of spinning, so a stalled source costs zero CPU until it wakes us.
+pub fn block_on<M>(mut source: M) -> Vec<M::Item>
+where
+    M: MultiplexedIterator,
+{
+    let notifier = Notifier::new();
+    let mut out = Vec::new();
+    loop {
+        notifier.wait();
+        match source.next(notifier.clone()) {
+            Poll::Ready(Some(v)) => {
+                out.push(v);
+                // More might be ready right now -- re-arm and poll again.
+                notifier.notify();
+            }
+            Poll::Ready(None) => break,
+            // Parked: the clone we handed the source will fire when it's ready.
+            Poll::NotReady => {}
+        }
+    }
+    out
+}
+// ANCHOR_END: block_on
+
+// ANCHOR: multiplexed_ping_mars
+/// A source whose first `stalls` polls return `Poll::NotReady`, each scheduling
+/// a wake on a background timer thread, before finally delivering its single
+/// message. The poll counter lets tests prove the driver polled exactly
+/// `stalls + 2` times -- no busy-looping.
+pub struct PingMarsMux {
+    stalls: usize,
+    delivered: bool,
+    polls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl PingMarsMux {
+    pub fn new(stalls: usize) -> Self {
+        Self {
+            stalls,
+            delivered: false,
+            polls: std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        }
+    }
+
+    /// A shared handle to the poll counter, for observing the runtime's cadence.
+    pub fn polls(&self) -> std::sync::Arc<std::sync::atomic::AtomicUsize> {
+        std::sync::Arc::clone(&self.polls)
+    }
+}
+
+impl MultiplexedIterator for PingMarsMux {
+    type Item = &'static str;
+
+    fn next(&mut self, n: Notifier) -> Poll<Self::Item> {
+        self.polls
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+        if self.stalls > 0 {
+            self.stalls -= 1;
+            // Simulate a network round-trip: wake the driver from another thread
+            // after a short delay rather than making it poll in a loop.
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(5));
+                n.notify();
+            });
+            return Poll::NotReady;
+        }
+
+        if !self.delivered {
+            self.delivered = true;
+            return Poll::Ready(Some("Hello from Mars!"));
+        }
+
+        Poll::Ready(None)
+    }
+}
+// ANCHOR_END: multiplexed_ping_mars
+
 #[cfg(test)]
 #[rustfmt::skip]
 mod tests {
@@ -390,6 +1392,65 @@ assert_eq!(None, f());
 // ANCHOR_END: test_bounds_ext_closure
 }
 
+#[test]
+fn range_double_ended() {
+// ANCHOR: test_range_double_ended
+let mut it = Range::new(0usize, 6, 1);
+assert_eq!(Some(0), it.next());
+assert_eq!(Some(5), it.next_back());
+assert_eq!(Some(1), it.next());
+assert_eq!(Some(4), it.next_back());
+assert_eq!(Some(2), it.next());
+assert_eq!(Some(3), it.next_back());
+assert_eq!(None, it.next()); // the two ends met exactly once
+assert_eq!(None, it.next_back());
+
+// A span that isn't a multiple of `incr`: both ends must stay on the
+// `0, 2, 4` grid `next` produces and never underflow past the front.
+let mut it = Range::new(0usize, 5, 2);
+assert_eq!(Some(4), it.next_back());
+assert_eq!(Some(0), it.next());
+assert_eq!(Some(2), it.next_back());
+assert_eq!(None, it.next());
+assert_eq!(None, it.next_back());
+// ANCHOR_END: test_range_double_ended
+}
+
+#[test]
+fn bounds_double_ended() {
+// ANCHOR: test_bounds_double_ended
+let mut it = Bounds::new(Range::new(0usize, 20, 1), 5, 8);
+assert_eq!(Some(5), it.next());
+assert_eq!(Some(7), it.next_back());
+assert_eq!(Some(6), it.next());
+assert_eq!(None, it.next());
+assert_eq!(None, it.next_back());
+// ANCHOR_END: test_bounds_double_ended
+}
+
+#[test]
+fn range_double_ended_closure() {
+// ANCHOR: test_range_double_ended_closure
+let (mut front, mut back) = range_de::new(0usize, 6, 1);
+assert_eq!(Some(0), front());
+assert_eq!(Some(5), back());
+assert_eq!(Some(1), front());
+assert_eq!(Some(4), back());
+assert_eq!(Some(2), front());
+assert_eq!(Some(3), back());
+assert_eq!(None, front());
+assert_eq!(None, back());
+
+// Same off-grid span as the struct twin, same grid-aligned result.
+let (mut front, mut back) = range_de::new(0usize, 5, 2);
+assert_eq!(Some(4), back());
+assert_eq!(Some(0), front());
+assert_eq!(Some(2), back());
+assert_eq!(None, front());
+assert_eq!(None, back());
+// ANCHOR_END: test_range_double_ended_closure
+}
+
 #[test]
 fn filter_ext() {
 // ANCHOR: test_filter_ext
@@ -398,9 +1459,174 @@ assert_eq!(Some(5), it.next());
 assert_eq!(Some(6), it.next());
 assert_eq!(Some(7), it.next());
 assert_eq!(None, it.next());
+
+let mut f = range_fn::new(1usize, 20, 1).filter(|&v| v >= 5 && v < 8);
+assert_eq!(Some(5), f());
+assert_eq!(Some(6), f());
+assert_eq!(Some(7), f());
+assert_eq!(None, f());
 // ANCHOR_END: test_filter_ext
 }
 
+#[test]
+fn map() {
+// ANCHOR: test_map
+let mut it = Range::new(0usize, 4, 1).map_with(|v| v * 10);
+assert_eq!(Some(0), it.next());
+assert_eq!(Some(10), it.next());
+assert_eq!(Some(20), it.next());
+assert_eq!(Some(30), it.next());
+assert_eq!(None, it.next());
+
+let mut f = range_fn::new(0usize, 4, 1).map(|v| v * 10);
+assert_eq!(Some(0), f());
+assert_eq!(Some(10), f());
+assert_eq!(Some(20), f());
+assert_eq!(Some(30), f());
+assert_eq!(None, f());
+// ANCHOR_END: test_map
+}
+
+#[test]
+fn zip() {
+// ANCHOR: test_zip
+let mut it = Range::new(0usize, 3, 1).zip_with(Range::new(10usize, 13, 1));
+assert_eq!(Some((0, 10)), it.next());
+assert_eq!(Some((1, 11)), it.next());
+assert_eq!(Some((2, 12)), it.next());
+assert_eq!(None, it.next());
+
+let mut f = range_fn::new(0usize, 3, 1).zip(range_fn::new(10usize, 13, 1));
+assert_eq!(Some((0, 10)), f());
+assert_eq!(Some((1, 11)), f());
+assert_eq!(Some((2, 12)), f());
+assert_eq!(None, f());
+// ANCHOR_END: test_zip
+}
+
+#[test]
+fn chain() {
+// ANCHOR: test_chain
+let mut it = Range::new(0usize, 2, 1).chain_with(Range::new(10usize, 12, 1));
+assert_eq!(Some(0), it.next());
+assert_eq!(Some(1), it.next());
+assert_eq!(Some(10), it.next());
+assert_eq!(Some(11), it.next());
+assert_eq!(None, it.next());
+
+let mut f = range_fn::new(0usize, 2, 1).chain(range_fn::new(10usize, 12, 1));
+assert_eq!(Some(0), f());
+assert_eq!(Some(1), f());
+assert_eq!(Some(10), f());
+assert_eq!(Some(11), f());
+assert_eq!(None, f());
+// ANCHOR_END: test_chain
+}
+
+#[test]
+fn enumerate() {
+// ANCHOR: test_enumerate
+let mut it = Range::new(5usize, 8, 1).enumerate_with();
+assert_eq!(Some((0, 5)), it.next());
+assert_eq!(Some((1, 6)), it.next());
+assert_eq!(Some((2, 7)), it.next());
+assert_eq!(None, it.next());
+
+let mut f = range_fn::new(5usize, 8, 1).enumerate();
+assert_eq!(Some((0, 5)), f());
+assert_eq!(Some((1, 6)), f());
+assert_eq!(Some((2, 7)), f());
+assert_eq!(None, f());
+// ANCHOR_END: test_enumerate
+}
+
+#[test]
+fn take() {
+// ANCHOR: test_take
+let mut it = Range::new(0usize, 100, 1).take_with(3);
+assert_eq!(Some(0), it.next());
+assert_eq!(Some(1), it.next());
+assert_eq!(Some(2), it.next());
+assert_eq!(None, it.next());
+
+let mut f = range_fn::new(0usize, 100, 1).take(3);
+assert_eq!(Some(0), f());
+assert_eq!(Some(1), f());
+assert_eq!(Some(2), f());
+assert_eq!(None, f());
+// ANCHOR_END: test_take
+}
+
+#[test]
+fn skip() {
+// ANCHOR: test_skip
+let mut it = Range::new(0usize, 5, 1).skip_with(2);
+assert_eq!(Some(2), it.next());
+assert_eq!(Some(3), it.next());
+assert_eq!(Some(4), it.next());
+assert_eq!(None, it.next());
+
+let mut f = range_fn::new(0usize, 5, 1).skip(2);
+assert_eq!(Some(2), f());
+assert_eq!(Some(3), f());
+assert_eq!(Some(4), f());
+assert_eq!(None, f());
+// ANCHOR_END: test_skip
+}
+
+#[test]
+fn fold() {
+// ANCHOR: test_fold
+let sum = Range::new(1usize, 5, 1).fold_with(0, |acc, v| acc + v);
+assert_eq!(10, sum);
+
+let sum = range_fn::new(1usize, 5, 1).fold(0, |acc, v| acc + v);
+assert_eq!(10, sum);
+// ANCHOR_END: test_fold
+}
+
+#[test]
+fn adapter_sizes() {
+// ANCHOR: test_adapter_sizes
+use std::mem::size_of_val;
+
+// Each `*_fn` closure captures exactly the fields its struct twin stores, so
+// neither representation pays an overhead the other avoids.
+let it = Range::new(0usize, 10, 1).map_with(|v| v + 1);
+let f = map_fn::new(range_fn::new(0usize, 10, 1), |v| v + 1);
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+let it = Range::new(0usize, 10, 1).take_with(3);
+let f = take_fn::new(range_fn::new(0usize, 10, 1), 3);
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+let it = Range::new(0usize, 10, 1).skip_with(3);
+let f = skip_fn::new(range_fn::new(0usize, 10, 1), 3);
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+let it = Range::new(0usize, 10, 1).enumerate_with();
+let f = enumerate_fn::new(range_fn::new(0usize, 10, 1));
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+let it = Range::new(0usize, 10, 1).filter_with(|&v| v >= 3 && v < 7);
+let f = filter_fn::new(range_fn::new(0usize, 10, 1), |&v| v >= 3 && v < 7);
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+let it = Range::new(0usize, 10, 1).bounds(3usize, 7);
+let f = bounds_fn::new(range_fn::new(0usize, 10, 1), 3usize, 7);
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+// The two-input adapters keep parity too: each captures both halves.
+let it = Range::new(0usize, 10, 1).zip_with(Range::new(0usize, 10, 2));
+let f = zip_fn::new(range_fn::new(0usize, 10, 1), range_fn::new(0usize, 10, 2));
+assert_eq!(size_of_val(&it), size_of_val(&f));
+
+let it = Range::new(0usize, 10, 1).chain_with(Range::new(10usize, 20, 1));
+let f = chain_fn::new(range_fn::new(0usize, 10, 1), range_fn::new(10usize, 20, 1));
+assert_eq!(size_of_val(&it), size_of_val(&f));
+// ANCHOR_END: test_adapter_sizes
+}
+
 #[test]
 fn empty_closure() {
 // ANCHOR: test_empty_closure
@@ -566,4 +1792,41 @@ assert_eq!(Some(13), it.next());
 assert_eq!(None, it.next());
 // ANCHOR_END: test_iter_to_closure_to_iter
 }
+
+#[test]
+fn multiplexed_from_iter() {
+// ANCHOR: test_multiplexed_from_iter
+let src = from_iter(Range::new(0usize, 10, 1)).bounds(3, 7).map(|v| v * 2);
+assert_eq!(vec![6, 8, 10, 12], block_on(src));
+
+let src = from_iter(Range::new(0usize, 10, 1)).filter(|&v| v % 2 == 0);
+assert_eq!(vec![0, 2, 4, 6, 8], block_on(src));
+// ANCHOR_END: test_multiplexed_from_iter
+}
+
+#[test]
+fn multiplexed_ping_mars() {
+// ANCHOR: test_multiplexed_ping_mars
+use std::sync::atomic::Ordering;
+
+let src = PingMarsMux::new(3);
+let polls = src.polls();
+
+assert_eq!(vec!["Hello from Mars!"], block_on(src));
+// 3 stalls + 1 value + 1 terminator: the driver parked, it didn't spin.
+assert_eq!(3 + 2, polls.load(Ordering::SeqCst));
+// ANCHOR_END: test_multiplexed_ping_mars
+}
+
+#[test]
+fn const_bounds_table() {
+// ANCHOR: test_const_bounds_table
+// Drained entirely at compile time: `ARR` lives in the binary as data.
+const ARR: [usize; 3] = const { super::const_bounds_table::<3>(20, 5, 8) };
+
+let runtime: Vec<usize> = Range::new(0usize, 20, 1).bounds(5, 8).collect();
+assert_eq!(ARR.to_vec(), runtime);
+assert_eq!([5, 6, 7], ARR);
+// ANCHOR_END: test_const_bounds_table
+}
 }